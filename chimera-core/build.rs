@@ -1,23 +1,49 @@
+use std::path::PathBuf;
+
+#[path = "build/proto_discovery.rs"]
+mod proto_discovery;
+
+use proto_discovery::{collect_protos, locate_include_dir, read_protoignore};
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Compile the shared proto file into Rust code
-    // Railway build: proto file is at ./proto/chimera.proto (copied by Dockerfile)
-    // Local dev: proto file is at ../@proto/chimera.proto
-    let (proto_path, include_dir) = if std::path::Path::new("./proto/chimera.proto").exists() {
-        ("./proto/chimera.proto", "./proto")
-    } else if std::path::Path::new("../@proto/chimera.proto").exists() {
-        ("../@proto/chimera.proto", "../@proto")
-    } else {
-        return Err("chimera.proto not found. Expected ./proto/chimera.proto or ../@proto/chimera.proto".into());
-    };
-    
+    println!("cargo:rerun-if-env-changed=CHIMERA_PROTO_DIR");
+
+    // With `--no-default-features` the `grpc` feature is off: skip proto
+    // discovery and compilation entirely, so the tonic/prost runtime crates
+    // stay out of the build and the crate falls back to its local
+    // JSON/NDJSON stub backend. The build-dependencies (tonic-build,
+    // protoc-bin-vendored) are still built in that mode; only their use here
+    // is skipped.
+    if std::env::var_os("CARGO_FEATURE_GRPC").is_none() {
+        return Ok(());
+    }
+
+    if std::env::var_os("PROTOC").is_none() {
+        // No system protoc assumed: point prost-build at the vendored
+        // binary so `cargo build` works without an extra install step.
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+
+    let include_dir = locate_include_dir()?;
+    let ignore_patterns = read_protoignore(&include_dir);
+    let proto_paths = collect_protos(&include_dir, &ignore_patterns)?;
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    let descriptor_path = out_dir.join("chimera_descriptor.bin");
+
+    // Cargo sets CARGO_FEATURE_<NAME> for every enabled feature, so the
+    // `mock-server` feature flips build_server(true) without build.rs
+    // needing its own copy of the feature list.
+    let build_server = std::env::var_os("CARGO_FEATURE_MOCK_SERVER").is_some();
+
     tonic_build::configure()
-        .build_server(false)  // We're a client, not a server
-        .compile_protos(
-            &[proto_path],
-            &[include_dir],
-        )?;
-    
-    println!("cargo:rerun-if-changed={}", proto_path);
-    
+        .build_server(build_server)
+        .file_descriptor_set_path(&descriptor_path)
+        .compile_protos(&proto_paths, &[&include_dir])?;
+
+    for proto_path in &proto_paths {
+        println!("cargo:rerun-if-changed={}", proto_path.display());
+    }
+
     Ok(())
 }
@@ -0,0 +1,140 @@
+//! Proto file discovery for `build.rs`: picks the include directory
+//! (`CHIMERA_PROTO_DIR` override or one of the two historical fallback
+//! paths), applies `.protoignore`, and walks it collecting every
+//! `*.proto` file.
+//!
+//! Pulled out of `build.rs` proper (and included there via `#[path]`) so
+//! these pure functions can be exercised by `tests/proto_discovery.rs`
+//! without needing a real build-script invocation.
+
+use std::path::{Path, PathBuf};
+
+/// Picks the directory to search for `.proto` files.
+///
+/// `CHIMERA_PROTO_DIR` takes priority so non-standard layouts and monorepos
+/// can point wherever their protos live. Otherwise we fall back to the two
+/// locations this crate has always shipped with:
+///   - Railway build: proto file is at ./proto/chimera.proto (copied by Dockerfile)
+///   - Local dev: proto file is at ../@proto/chimera.proto
+pub(crate) fn locate_include_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if let Some(dir) = std::env::var_os("CHIMERA_PROTO_DIR") {
+        let dir = PathBuf::from(dir);
+        if dir.exists() {
+            return Ok(dir);
+        }
+        return Err(format!(
+            "CHIMERA_PROTO_DIR was set to {}, but that directory does not exist",
+            dir.display()
+        )
+        .into());
+    }
+
+    let candidates = ["./proto", "../@proto"];
+    for candidate in candidates {
+        if Path::new(candidate).join("chimera.proto").exists() {
+            return Ok(PathBuf::from(candidate));
+        }
+    }
+
+    Err(format!(
+        "chimera.proto not found. Searched: {} (set CHIMERA_PROTO_DIR to override)",
+        candidates.join(", ")
+    )
+    .into())
+}
+
+/// Reads `.protoignore` from `include_dir`, if present: one glob pattern per
+/// line, matched against each proto file's path relative to `include_dir`.
+/// Blank lines and `#`-prefixed comments are skipped, mirroring `.gitignore`.
+pub(crate) fn read_protoignore(include_dir: &Path) -> Vec<String> {
+    let path = include_dir.join(".protoignore");
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Walks `include_dir` collecting every `*.proto` file, skipping any whose
+/// path (relative to `include_dir`) matches a `.protoignore` pattern.
+pub(crate) fn collect_protos(
+    include_dir: &Path,
+    ignore_patterns: &[String],
+) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let mut protos = Vec::new();
+    walk(include_dir, include_dir, ignore_patterns, &mut protos)?;
+
+    if protos.is_empty() {
+        return Err(format!(
+            "no *.proto files found under {} (after applying .protoignore)",
+            include_dir.display()
+        )
+        .into());
+    }
+
+    protos.sort();
+    Ok(protos)
+}
+
+fn walk(
+    root: &Path,
+    dir: &Path,
+    ignore_patterns: &[String],
+    protos: &mut Vec<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            walk(root, &path, ignore_patterns, protos)?;
+            continue;
+        }
+
+        if path.extension().and_then(|ext| ext.to_str()) != Some("proto") {
+            continue;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        if ignore_patterns
+            .iter()
+            .any(|pattern| glob_match(pattern, &relative.to_string_lossy()))
+        {
+            continue;
+        }
+
+        protos.push(path);
+    }
+
+    Ok(())
+}
+
+/// Minimal glob matcher supporting `*` (any run of characters, including
+/// none) and `?` (exactly one character). That covers `.protoignore`'s
+/// vendored/experimental-proto exclusion patterns without pulling in a
+/// dependency just for build.rs.
+pub(crate) fn glob_match(pattern: &str, candidate: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    glob_match_inner(&pattern, &candidate)
+}
+
+fn glob_match_inner(pattern: &[char], candidate: &[char]) -> bool {
+    match pattern.first() {
+        None => candidate.is_empty(),
+        Some('*') => {
+            glob_match_inner(&pattern[1..], candidate)
+                || (!candidate.is_empty() && glob_match_inner(pattern, &candidate[1..]))
+        }
+        Some('?') => !candidate.is_empty() && glob_match_inner(&pattern[1..], &candidate[1..]),
+        Some(c) => candidate.first() == Some(c) && glob_match_inner(&pattern[1..], &candidate[1..]),
+    }
+}
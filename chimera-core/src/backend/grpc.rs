@@ -0,0 +1,31 @@
+//! Default backend: sends scrape records to chimera over gRPC.
+
+use tonic::transport::Channel;
+
+use super::{BackendError, ScrapeRecord};
+use crate::chimera::chimera_service_client::ChimeraServiceClient;
+use crate::chimera::ScrapeRequest;
+
+/// Sends scrape records to a chimera backend over an established channel.
+pub struct GrpcBackend {
+    client: ChimeraServiceClient<Channel>,
+}
+
+impl GrpcBackend {
+    pub fn new(client: ChimeraServiceClient<Channel>) -> Self {
+        Self { client }
+    }
+
+    /// Submits `record` via the chimera `submit_scrape` RPC.
+    pub async fn record(&mut self, record: &ScrapeRecord) -> Result<(), BackendError> {
+        self.client
+            .submit_scrape(ScrapeRequest {
+                url: record.url.clone(),
+                body: record.body.clone(),
+            })
+            .await
+            .map_err(|status| BackendError::Grpc(Box::new(status)))?;
+
+        Ok(())
+    }
+}
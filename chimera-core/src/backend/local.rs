@@ -0,0 +1,44 @@
+//! Offline backend: appends scrape records to a local NDJSON file instead
+//! of sending them to chimera.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::{BackendError, ScrapeRecord};
+
+/// Records scrape results as newline-delimited JSON, one record per line,
+/// appending to `path`. Used in place of [`GrpcBackend`](super::GrpcBackend)
+/// when the crate is built with `--no-default-features`.
+pub struct LocalFileBackend {
+    file: Mutex<std::fs::File>,
+}
+
+impl LocalFileBackend {
+    /// Opens (creating if needed) `path` for appending.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, BackendError> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Appends `record` as a single NDJSON line.
+    pub fn record(&self, record: &ScrapeRecord) -> Result<(), BackendError> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().unwrap();
+        file.write_all(&line)?;
+        Ok(())
+    }
+}
+
+/// Default fixture path used when a job doesn't specify one explicitly.
+pub fn default_fixture_path() -> PathBuf {
+    PathBuf::from("scrape-results.ndjson")
+}
@@ -0,0 +1,60 @@
+//! Where scrape results go once a job captures them.
+//!
+//! By default (the `grpc` feature) that's the chimera backend over tonic.
+//! With `--no-default-features` there is no reachable chimera endpoint, so
+//! results fall back to [`LocalFileBackend`], which appends them as NDJSON
+//! to a local file — enough to test scrape logic and record fixtures
+//! offline.
+
+#[cfg(feature = "grpc")]
+mod grpc;
+mod local;
+
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcBackend;
+pub use local::{default_fixture_path, LocalFileBackend};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A single scrape result, independent of which backend records it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeRecord {
+    pub url: String,
+    pub captured_at_unix_ms: i64,
+    pub body: Vec<u8>,
+}
+
+/// Errors common to every backend implementation.
+#[derive(Debug)]
+pub enum BackendError {
+    Io(std::io::Error),
+    Serialize(serde_json::Error),
+    #[cfg(feature = "grpc")]
+    Grpc(Box<tonic::Status>),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Io(err) => write!(f, "backend I/O error: {err}"),
+            BackendError::Serialize(err) => write!(f, "failed to serialize scrape record: {err}"),
+            #[cfg(feature = "grpc")]
+            BackendError::Grpc(status) => write!(f, "chimera backend RPC failed: {status}"),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+impl From<std::io::Error> for BackendError {
+    fn from(err: std::io::Error) -> Self {
+        BackendError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BackendError {
+    fn from(err: serde_json::Error) -> Self {
+        BackendError::Serialize(err)
+    }
+}
@@ -0,0 +1,21 @@
+mod backend;
+#[cfg(feature = "grpc")]
+mod reflection;
+#[cfg(feature = "grpc")]
+mod transport;
+
+pub use backend::{default_fixture_path, BackendError, LocalFileBackend, ScrapeRecord};
+#[cfg(feature = "grpc")]
+pub use backend::GrpcBackend;
+#[cfg(feature = "grpc")]
+pub use reflection::{MethodDescriptor, ReflectionError, ReflectionRegistry};
+#[cfg(feature = "grpc")]
+pub use transport::{BackoffConfig, WsCapture, WsCaptureError};
+
+/// Generated client (and, with the `mock-server` feature, server) code for
+/// the chimera proto. Only built when the `grpc` feature pulls in tonic and
+/// compiles the chimera proto in `build.rs`.
+#[cfg(feature = "grpc")]
+pub mod chimera {
+    tonic::include_proto!("chimera");
+}
@@ -0,0 +1,321 @@
+//! Runtime reflection over the chimera gRPC service definition.
+//!
+//! `build.rs` asks `tonic_build` to emit a serialized `FileDescriptorSet`
+//! alongside the generated client code. We embed that blob directly into the
+//! binary so callers can look up RPC methods by their fully-qualified name
+//! (e.g. when a scrape job config names an RPC as a string) without needing
+//! a live reflection service or hard-coded method tables.
+
+use prost::Message;
+use prost_types::{DescriptorProto, EnumDescriptorProto, FileDescriptorProto, FileDescriptorSet};
+use std::collections::HashMap;
+use std::fmt;
+
+/// The descriptor set emitted by `build.rs` for the chimera proto sources.
+static DESCRIPTOR_SET_BYTES: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/chimera_descriptor.bin"));
+
+/// Input/output type information for a single RPC method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodDescriptor {
+    /// Fully-qualified method name, e.g. `chimera.ChimeraService.SubmitScrape`.
+    pub full_name: String,
+    /// Fully-qualified input message type, e.g. `.chimera.ScrapeRequest`.
+    pub input_type: String,
+    /// Fully-qualified output message type, e.g. `.chimera.ScrapeResponse`.
+    pub output_type: String,
+    /// Whether the client streams multiple requests for this method.
+    pub client_streaming: bool,
+    /// Whether the server streams multiple responses for this method.
+    pub server_streaming: bool,
+}
+
+/// Errors returned while parsing or querying the embedded descriptor set.
+#[derive(Debug)]
+pub enum ReflectionError {
+    /// The embedded bytes were not a valid `FileDescriptorSet`.
+    Decode(prost::DecodeError),
+    /// No method with the requested fully-qualified name was found.
+    MethodNotFound(String),
+    /// No message type with the requested fully-qualified name was found.
+    MessageNotFound(String),
+    /// No enum type with the requested fully-qualified name was found.
+    EnumNotFound(String),
+}
+
+impl fmt::Display for ReflectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectionError::Decode(err) => write!(f, "failed to decode descriptor set: {err}"),
+            ReflectionError::MethodNotFound(name) => {
+                write!(f, "no such chimera RPC method: {name}")
+            }
+            ReflectionError::MessageNotFound(name) => {
+                write!(f, "no such chimera message type: {name}")
+            }
+            ReflectionError::EnumNotFound(name) => {
+                write!(f, "no such chimera enum type: {name}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReflectionError {}
+
+/// Indexes the services and methods of the chimera proto by fully-qualified
+/// name, so a scrape job config can name an RPC as a string and have it
+/// validated (and its request/response types looked up) before the call is
+/// made.
+pub struct ReflectionRegistry {
+    methods: HashMap<String, MethodDescriptor>,
+    messages: HashMap<String, DescriptorProto>,
+    enums: HashMap<String, EnumDescriptorProto>,
+}
+
+impl ReflectionRegistry {
+    /// Parses the descriptor set embedded at build time and indexes every
+    /// service method, message type, and enum type it declares (recursing
+    /// into nested messages and enums, a common proto pattern).
+    pub fn load() -> Result<Self, ReflectionError> {
+        let descriptor_set =
+            FileDescriptorSet::decode(DESCRIPTOR_SET_BYTES).map_err(ReflectionError::Decode)?;
+
+        let mut methods = HashMap::new();
+        let mut messages = HashMap::new();
+        let mut enums = HashMap::new();
+        for file in &descriptor_set.file {
+            index_file(file, &mut methods, &mut messages, &mut enums);
+        }
+
+        Ok(Self {
+            methods,
+            messages,
+            enums,
+        })
+    }
+
+    /// Looks up a method by its fully-qualified name
+    /// (`<package>.<Service>.<Method>`), returning its input/output types.
+    pub fn method(&self, full_name: &str) -> Result<&MethodDescriptor, ReflectionError> {
+        self.methods
+            .get(full_name)
+            .ok_or_else(|| ReflectionError::MethodNotFound(full_name.to_string()))
+    }
+
+    /// Returns the fully-qualified names of every method in the registry,
+    /// primarily for a `--list-methods` command.
+    pub fn method_names(&self) -> impl Iterator<Item = &str> {
+        self.methods.keys().map(String::as_str)
+    }
+
+    /// Looks up a message type by its fully-qualified name, e.g.
+    /// `.chimera.ScrapeRequest` as returned in `MethodDescriptor::input_type`.
+    /// Exposes the message's field definitions so a caller that has
+    /// validated an RPC name can also construct the request it expects.
+    pub fn message(&self, full_name: &str) -> Result<&DescriptorProto, ReflectionError> {
+        self.messages
+            .get(full_name)
+            .ok_or_else(|| ReflectionError::MessageNotFound(full_name.to_string()))
+    }
+
+    /// Looks up an enum type by its fully-qualified name, mirroring `message`.
+    pub fn enum_type(&self, full_name: &str) -> Result<&EnumDescriptorProto, ReflectionError> {
+        self.enums
+            .get(full_name)
+            .ok_or_else(|| ReflectionError::EnumNotFound(full_name.to_string()))
+    }
+}
+
+fn index_file(
+    file: &FileDescriptorProto,
+    methods: &mut HashMap<String, MethodDescriptor>,
+    messages: &mut HashMap<String, DescriptorProto>,
+    enums: &mut HashMap<String, EnumDescriptorProto>,
+) {
+    let package = file.package.as_deref().unwrap_or_default();
+    let file_prefix = if package.is_empty() {
+        String::new()
+    } else {
+        format!(".{package}")
+    };
+
+    for message in &file.message_type {
+        index_message(message, &file_prefix, messages, enums);
+    }
+
+    for enum_type in &file.enum_type {
+        let enum_name = enum_type.name.as_deref().unwrap_or_default();
+        enums.insert(format!("{file_prefix}.{enum_name}"), enum_type.clone());
+    }
+
+    for service in &file.service {
+        let service_name = service.name.as_deref().unwrap_or_default();
+        let qualified_service = if package.is_empty() {
+            service_name.to_string()
+        } else {
+            format!("{package}.{service_name}")
+        };
+
+        for method in &service.method {
+            let method_name = method.name.as_deref().unwrap_or_default();
+            let full_name = format!("{qualified_service}.{method_name}");
+
+            methods.insert(
+                full_name.clone(),
+                MethodDescriptor {
+                    full_name,
+                    input_type: method.input_type.clone().unwrap_or_default(),
+                    output_type: method.output_type.clone().unwrap_or_default(),
+                    client_streaming: method.client_streaming.unwrap_or(false),
+                    server_streaming: method.server_streaming.unwrap_or(false),
+                },
+            );
+        }
+    }
+}
+
+/// Indexes `message` under `parent` (its containing file or message's
+/// fully-qualified name) and recurses into `nested_type`/`enum_type`, since
+/// a message nested inside another is a common proto pattern.
+fn index_message(
+    message: &DescriptorProto,
+    parent: &str,
+    messages: &mut HashMap<String, DescriptorProto>,
+    enums: &mut HashMap<String, EnumDescriptorProto>,
+) {
+    let message_name = message.name.as_deref().unwrap_or_default();
+    let qualified = format!("{parent}.{message_name}");
+
+    for nested in &message.nested_type {
+        index_message(nested, &qualified, messages, enums);
+    }
+
+    for enum_type in &message.enum_type {
+        let enum_name = enum_type.name.as_deref().unwrap_or_default();
+        enums.insert(format!("{qualified}.{enum_name}"), enum_type.clone());
+    }
+
+    messages.insert(qualified, message.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submit_scrape_resolves_with_its_input_and_output_types() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let method = registry
+            .method("chimera.ChimeraService.SubmitScrape")
+            .expect("SubmitScrape should be indexed");
+
+        assert_eq!(method.input_type, ".chimera.ScrapeRequest");
+        assert_eq!(method.output_type, ".chimera.ScrapeResponse");
+        assert!(!method.client_streaming);
+        assert!(!method.server_streaming);
+    }
+
+    #[test]
+    fn stream_ingest_is_indexed_as_client_streaming() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let method = registry
+            .method("chimera.ChimeraService.StreamIngest")
+            .expect("StreamIngest should be indexed");
+
+        assert_eq!(method.input_type, ".chimera.IngestFrame");
+        assert_eq!(method.output_type, ".chimera.IngestAck");
+        assert!(method.client_streaming);
+        assert!(!method.server_streaming);
+    }
+
+    #[test]
+    fn submit_scrape_input_type_resolves_to_its_message_fields() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let method = registry
+            .method("chimera.ChimeraService.SubmitScrape")
+            .expect("SubmitScrape should be indexed");
+        let input = registry
+            .message(&method.input_type)
+            .expect("ScrapeRequest should be indexed");
+
+        let field_names: Vec<&str> = input
+            .field
+            .iter()
+            .map(|field| field.name.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(field_names, vec!["url", "body"]);
+    }
+
+    #[test]
+    fn index_file_recurses_into_nested_messages_and_enums() {
+        let nested_enum = EnumDescriptorProto {
+            name: Some("Status".to_string()),
+            ..Default::default()
+        };
+        let nested_message = DescriptorProto {
+            name: Some("Metadata".to_string()),
+            ..Default::default()
+        };
+        let top_message = DescriptorProto {
+            name: Some("ScrapeRequest".to_string()),
+            nested_type: vec![nested_message],
+            enum_type: vec![nested_enum],
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            package: Some("chimera".to_string()),
+            message_type: vec![top_message],
+            ..Default::default()
+        };
+
+        let mut methods = HashMap::new();
+        let mut messages = HashMap::new();
+        let mut enums = HashMap::new();
+        index_file(&file, &mut methods, &mut messages, &mut enums);
+
+        assert!(messages.contains_key(".chimera.ScrapeRequest"));
+        assert!(messages.contains_key(".chimera.ScrapeRequest.Metadata"));
+        assert!(enums.contains_key(".chimera.ScrapeRequest.Status"));
+    }
+
+    #[test]
+    fn unknown_message_name_is_not_found() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let err = registry.message(".chimera.DoesNotExist").unwrap_err();
+
+        assert!(
+            matches!(err, ReflectionError::MessageNotFound(name) if name == ".chimera.DoesNotExist")
+        );
+    }
+
+    #[test]
+    fn unknown_method_name_is_not_found() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let err = registry
+            .method("chimera.ChimeraService.DoesNotExist")
+            .unwrap_err();
+
+        assert!(matches!(err, ReflectionError::MethodNotFound(name) if name == "chimera.ChimeraService.DoesNotExist"));
+    }
+
+    #[test]
+    fn method_names_lists_every_indexed_method() {
+        let registry = ReflectionRegistry::load().expect("embedded descriptor set should parse");
+
+        let mut names: Vec<&str> = registry.method_names().collect();
+        names.sort_unstable();
+
+        assert_eq!(
+            names,
+            vec![
+                "chimera.ChimeraService.StreamIngest",
+                "chimera.ChimeraService.SubmitScrape",
+            ]
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Capture transports that feed scraped content into the chimera pipeline.
+//!
+//! HTTP request/response scraping goes straight through the chimera client;
+//! push-based sources (WebSockets today) need their own connection
+//! lifecycle, so they live here instead.
+
+pub mod ws;
+
+pub use ws::{BackoffConfig, WsCapture, WsCaptureError};
@@ -0,0 +1,254 @@
+//! WebSocket capture for push-based scrape sources.
+//!
+//! Some targets (live chat, tickers, live-updating dashboards) never return
+//! a complete HTML body — the content arrives as a stream of WebSocket
+//! frames instead. `WsCapture` connects to such a target, performs the
+//! Upgrade handshake, reassembles frames into complete messages, and
+//! streams them to the backend over a client-streaming chimera RPC,
+//! reconnecting with backoff if the connection drops.
+
+use std::fmt;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::chimera::chimera_service_client::ChimeraServiceClient;
+use crate::chimera::IngestFrame;
+
+/// Reconnect backoff: delays start at `initial`, double each failed attempt
+/// (capped at `max`), and reset to `initial` after a successful connection.
+#[derive(Debug, Clone, Copy)]
+pub struct BackoffConfig {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(250),
+            max: Duration::from_secs(30),
+        }
+    }
+}
+
+impl BackoffConfig {
+    fn next_delay(&self, current: Duration) -> Duration {
+        std::cmp::min(current * 2, self.max)
+    }
+}
+
+/// Errors surfaced by `WsCapture`. Failed connects and dropped connections
+/// (`Connect`, `Read`) are handled internally by `run` via
+/// reconnect-with-backoff and never reach the caller as errors; the other
+/// variants are the failures that end the capture outright.
+#[derive(Debug)]
+pub enum WsCaptureError {
+    InvalidUrl(tokio_tungstenite::tungstenite::Error),
+    InvalidSubprotocol(String),
+    /// The URL parsed fine but the handshake itself failed: connection
+    /// refused, TLS failure, or the peer rejected the Upgrade request.
+    /// Distinguished from `InvalidUrl` so a down endpoint doesn't get logged
+    /// as a malformed URL.
+    Connect(tokio_tungstenite::tungstenite::Error),
+    /// The connection dropped with an I/O or protocol error rather than a
+    /// clean close. Distinguished from a clean close so `run` backs off
+    /// before reconnecting instead of retrying immediately.
+    Read(tokio_tungstenite::tungstenite::Error),
+    Grpc(tonic::Status),
+}
+
+impl fmt::Display for WsCaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WsCaptureError::InvalidUrl(err) => write!(f, "invalid websocket URL: {err}"),
+            WsCaptureError::InvalidSubprotocol(value) => {
+                write!(f, "invalid Sec-WebSocket-Protocol header value: {value}")
+            }
+            WsCaptureError::Connect(err) => write!(f, "failed to connect to websocket: {err}"),
+            WsCaptureError::Read(err) => write!(f, "websocket read error: {err}"),
+            WsCaptureError::Grpc(status) => write!(f, "chimera ingest RPC failed: {status}"),
+        }
+    }
+}
+
+impl std::error::Error for WsCaptureError {}
+
+/// Connects to a `ws://`/`wss://` source and streams reassembled messages
+/// into the chimera pipeline.
+pub struct WsCapture {
+    url: String,
+    subprotocol: Option<String>,
+    backoff: BackoffConfig,
+}
+
+impl WsCapture {
+    /// Creates a capture for `url`, using the default backoff schedule and
+    /// no subprotocol header.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            subprotocol: None,
+            backoff: BackoffConfig::default(),
+        }
+    }
+
+    /// Sets the `Sec-WebSocket-Protocol` header sent during the handshake.
+    pub fn subprotocol(mut self, subprotocol: impl Into<String>) -> Self {
+        self.subprotocol = Some(subprotocol.into());
+        self
+    }
+
+    /// Overrides the reconnect backoff schedule.
+    pub fn backoff(mut self, backoff: BackoffConfig) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Runs the capture until the URL or subprotocol turns out to be
+    /// invalid, `client` reports a gRPC error, or the process is shut down;
+    /// failed connects and dropped connections are retried with backoff
+    /// rather than returned as errors.
+    pub async fn run(
+        &self,
+        client: &mut ChimeraServiceClient<tonic::transport::Channel>,
+    ) -> Result<(), WsCaptureError> {
+        let mut delay = self.backoff.initial;
+
+        loop {
+            match self.capture_once(client).await {
+                Ok(()) => {
+                    // Clean close: reconnect immediately, resetting backoff.
+                    delay = self.backoff.initial;
+                }
+                Err(
+                    err @ (WsCaptureError::Grpc(_)
+                    | WsCaptureError::InvalidUrl(_)
+                    | WsCaptureError::InvalidSubprotocol(_)),
+                ) => return Err(err),
+                Err(err) => {
+                    if matches!(err, WsCaptureError::Read(_)) {
+                        // A read error means the connection was established
+                        // before it dropped, so the backoff starts over.
+                        delay = self.backoff.initial;
+                    }
+                    tracing::warn!(error = %err, delay_ms = %delay.as_millis(), "websocket capture dropped, reconnecting");
+                    tokio::time::sleep(delay).await;
+                    delay = self.backoff.next_delay(delay);
+                }
+            }
+        }
+    }
+
+    /// Holds a single connection open until it closes or errors, forwarding
+    /// every reassembled message to `client` as it arrives.
+    async fn capture_once(
+        &self,
+        client: &mut ChimeraServiceClient<tonic::transport::Channel>,
+    ) -> Result<(), WsCaptureError> {
+        let mut request = self
+            .url
+            .as_str()
+            .into_client_request()
+            .map_err(WsCaptureError::InvalidUrl)?;
+
+        if let Some(subprotocol) = &self.subprotocol {
+            request.headers_mut().insert(
+                "Sec-WebSocket-Protocol",
+                subprotocol
+                    .parse()
+                    .map_err(|_| WsCaptureError::InvalidSubprotocol(subprotocol.clone()))?,
+            );
+        }
+
+        let (ws_stream, _response) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(WsCaptureError::Connect)?;
+
+        let (mut sink, mut stream) = ws_stream.split();
+        let (frame_tx, frame_rx) = mpsc::channel::<IngestFrame>(64);
+
+        // The RPC only resolves once the outbound stream ends, so it has to
+        // run concurrently with the read loop below (which is the only
+        // place `frame_tx` is ever sent to) rather than being awaited first.
+        let outbound = tokio_stream::wrappers::ReceiverStream::new(frame_rx);
+        let mut ingest_client = client.clone();
+        let ingest_task = tokio::spawn(async move { ingest_client.stream_ingest(outbound).await });
+
+        let mut read_error = None;
+
+        while let Some(message) = stream.next().await {
+            let message = match message {
+                Ok(message) => message,
+                Err(err) => {
+                    read_error = Some(err);
+                    break;
+                }
+            };
+
+            match message {
+                Message::Text(text) => {
+                    let _ = frame_tx
+                        .send(IngestFrame {
+                            payload: text.into_bytes(),
+                        })
+                        .await;
+                }
+                Message::Binary(data) => {
+                    let _ = frame_tx.send(IngestFrame { payload: data }).await;
+                }
+                Message::Ping(payload) => {
+                    let _ = sink.send(Message::Pong(payload)).await;
+                }
+                Message::Pong(_) => {
+                    // Keepalive acknowledged; nothing to forward.
+                }
+                Message::Close(_) => break,
+                Message::Frame(_) => {
+                    // Raw frames are only surfaced when reading at the
+                    // protocol level directly; tungstenite reassembles
+                    // continuation frames into Text/Binary for us.
+                }
+            }
+        }
+
+        // Dropping the sender ends `outbound`, letting the RPC resolve.
+        drop(frame_tx);
+
+        let ingest_result = ingest_task.await.expect("chimera ingest task panicked");
+
+        if let Some(err) = read_error {
+            return Err(WsCaptureError::Read(err));
+        }
+
+        match ingest_result {
+            Ok(_ack) => Ok(()),
+            Err(status) => Err(WsCaptureError::Grpc(status)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_delay_doubles_until_the_cap_then_holds() {
+        let backoff = BackoffConfig {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(350),
+        };
+
+        let delay = backoff.next_delay(backoff.initial);
+        assert_eq!(delay, Duration::from_millis(200));
+
+        let delay = backoff.next_delay(delay);
+        assert_eq!(delay, Duration::from_millis(350), "350 < 400, so it caps");
+
+        let delay = backoff.next_delay(delay);
+        assert_eq!(delay, Duration::from_millis(350), "stays at the cap");
+    }
+}
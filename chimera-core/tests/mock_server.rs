@@ -0,0 +1,43 @@
+//! Exercises `MockChimeraServer` end-to-end: start it, submit a scrape
+//! through a connected client, and assert the canned response comes back.
+
+#![cfg(feature = "mock-server")]
+
+mod support;
+
+use chimera_core::chimera::{ScrapeRequest, ScrapeResponse};
+use support::MockChimeraServer;
+use tonic::{Request, Status};
+
+#[tokio::test]
+async fn submit_scrape_round_trips_through_the_mock_server() {
+    let server = MockChimeraServer::new(vec![Ok(ScrapeResponse { accepted: true })]);
+    let mut client = support::start(server).await;
+
+    let response = client
+        .submit_scrape(Request::new(ScrapeRequest {
+            url: "https://example.com".into(),
+            body: b"<html></html>".to_vec(),
+        }))
+        .await
+        .expect("submit_scrape should succeed")
+        .into_inner();
+
+    assert!(response.accepted);
+}
+
+#[tokio::test]
+async fn submit_scrape_surfaces_the_scripted_error() {
+    let server = MockChimeraServer::new(vec![Err(Status::unavailable("chimera is down"))]);
+    let mut client = support::start(server).await;
+
+    let status = client
+        .submit_scrape(Request::new(ScrapeRequest {
+            url: "https://example.com".into(),
+            body: Vec::new(),
+        }))
+        .await
+        .expect_err("submit_scrape should surface the scripted error");
+
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+}
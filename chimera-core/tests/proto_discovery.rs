@@ -0,0 +1,107 @@
+//! Exercises the proto discovery helpers `build.rs` relies on: multi-file
+//! collection, `.protoignore` exclusion, the `CHIMERA_PROTO_DIR` override,
+//! and the "no protos found" error path. These are pure functions pulled out
+//! of `build.rs` into `build/proto_discovery.rs` specifically so they can be
+//! unit-tested here without invoking a real build.
+
+#[path = "../build/proto_discovery.rs"]
+mod proto_discovery;
+
+use proto_discovery::{collect_protos, locate_include_dir, read_protoignore};
+use std::fs;
+use std::sync::Mutex;
+
+/// `CHIMERA_PROTO_DIR` is process-global state, so the tests that set it
+/// take this lock to avoid racing each other when run concurrently.
+static CHIMERA_PROTO_DIR_LOCK: Mutex<()> = Mutex::new(());
+
+fn write_proto(dir: &std::path::Path, relative: &str) {
+    let path = dir.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "syntax = \"proto3\";").unwrap();
+}
+
+#[test]
+fn collects_every_proto_file_across_nested_directories() {
+    let include_dir = tempfile::tempdir().unwrap();
+    write_proto(include_dir.path(), "chimera.proto");
+    write_proto(include_dir.path(), "nested/extra.proto");
+
+    let protos = collect_protos(include_dir.path(), &[]).unwrap();
+
+    let names: Vec<_> = protos
+        .iter()
+        .map(|p| p.strip_prefix(include_dir.path()).unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            std::path::Path::new("chimera.proto"),
+            std::path::Path::new("nested/extra.proto"),
+        ]
+    );
+}
+
+#[test]
+fn protoignore_excludes_a_matching_nested_file() {
+    let include_dir = tempfile::tempdir().unwrap();
+    write_proto(include_dir.path(), "chimera.proto");
+    write_proto(include_dir.path(), "vendor/third_party.proto");
+
+    let ignore_patterns = vec!["vendor/*".to_string()];
+    let protos = collect_protos(include_dir.path(), &ignore_patterns).unwrap();
+
+    assert_eq!(protos, vec![include_dir.path().join("chimera.proto")]);
+}
+
+#[test]
+fn read_protoignore_skips_blank_lines_and_comments() {
+    let include_dir = tempfile::tempdir().unwrap();
+    fs::write(
+        include_dir.path().join(".protoignore"),
+        "\n# a comment\nvendor/*\n\n",
+    )
+    .unwrap();
+
+    let patterns = read_protoignore(include_dir.path());
+
+    assert_eq!(patterns, vec!["vendor/*".to_string()]);
+}
+
+#[test]
+fn chimera_proto_dir_overrides_the_fallback_paths() {
+    let _guard = CHIMERA_PROTO_DIR_LOCK.lock().unwrap();
+    let include_dir = tempfile::tempdir().unwrap();
+    write_proto(include_dir.path(), "chimera.proto");
+
+    std::env::set_var("CHIMERA_PROTO_DIR", include_dir.path());
+    let located = locate_include_dir();
+    std::env::remove_var("CHIMERA_PROTO_DIR");
+
+    assert_eq!(located.unwrap(), include_dir.path());
+}
+
+#[test]
+fn chimera_proto_dir_pointing_at_a_missing_directory_is_an_error() {
+    let _guard = CHIMERA_PROTO_DIR_LOCK.lock().unwrap();
+    let missing = tempfile::tempdir().unwrap().path().join("does-not-exist");
+
+    std::env::set_var("CHIMERA_PROTO_DIR", &missing);
+    let located = locate_include_dir();
+    std::env::remove_var("CHIMERA_PROTO_DIR");
+
+    let err = located.unwrap_err();
+    assert!(err.to_string().contains(&missing.display().to_string()));
+}
+
+#[test]
+fn a_directory_with_no_protos_is_a_clear_error() {
+    let include_dir = tempfile::tempdir().unwrap();
+    fs::write(include_dir.path().join("README.md"), "not a proto").unwrap();
+
+    let err = collect_protos(include_dir.path(), &[]).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains(&include_dir.path().display().to_string()));
+}
@@ -0,0 +1,96 @@
+//! In-process mock chimera server for offline tests.
+//!
+//! Only compiled when the `mock-server` feature is enabled, which also
+//! flips `build.rs` into generating the server-side stubs. Tests start the
+//! mock over an in-memory duplex channel, point a client at it, and assert
+//! request/response round-trips without a live chimera backend.
+
+#![cfg(feature = "mock-server")]
+
+use chimera_core::chimera::chimera_service_client::ChimeraServiceClient;
+use chimera_core::chimera::chimera_service_server::{ChimeraService, ChimeraServiceServer};
+use chimera_core::chimera::{IngestAck, IngestFrame, ScrapeRequest, ScrapeResponse};
+use hyper_util::rt::TokioIo;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tonic::transport::{Channel, Endpoint, Server, Uri};
+use tonic::{Request, Response, Status, Streaming};
+
+/// A mock chimera service that replays a fixed queue of canned responses,
+/// one per incoming `submit_scrape` request, in order. `stream_ingest` does
+/// not use the queue; it acks with the number of frames it received.
+pub struct MockChimeraServer {
+    responses: Mutex<VecDeque<Result<ScrapeResponse, Status>>>,
+}
+
+impl MockChimeraServer {
+    /// Builds a mock that answers `submit_scrape` calls with `responses`,
+    /// one per call, in order. Panics if more calls arrive than scripted.
+    pub fn new(responses: Vec<Result<ScrapeResponse, Status>>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ChimeraService for MockChimeraServer {
+    async fn submit_scrape(
+        &self,
+        _request: Request<ScrapeRequest>,
+    ) -> Result<Response<ScrapeResponse>, Status> {
+        let next = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("mock chimera server received more requests than were scripted");
+        next.map(Response::new)
+    }
+
+    async fn stream_ingest(
+        &self,
+        request: Request<Streaming<IngestFrame>>,
+    ) -> Result<Response<IngestAck>, Status> {
+        let mut stream = request.into_inner();
+        let mut frames_received = 0u64;
+        while stream.message().await?.is_some() {
+            frames_received += 1;
+        }
+        Ok(Response::new(IngestAck { frames_received }))
+    }
+}
+
+/// Starts `server` on an in-memory duplex channel and returns a connected
+/// client, so tests never need a reachable port.
+pub async fn start(server: MockChimeraServer) -> ChimeraServiceClient<Channel> {
+    let (client_io, server_io) = tokio::io::duplex(4096);
+
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(ChimeraServiceServer::new(server))
+            .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io)))
+            .await
+            .expect("mock chimera server failed");
+    });
+
+    let mut client_io = Some(client_io);
+    let channel = Endpoint::try_from("http://[::]:50051")
+        .expect("static mock endpoint URI is always valid")
+        .connect_with_connector(tower::service_fn(move |_: Uri| {
+            let client_io = client_io.take();
+            async move {
+                client_io
+                    .ok_or_else(|| {
+                        std::io::Error::other(
+                            "mock chimera connector only supports a single connection",
+                        )
+                    })
+                    .map(TokioIo::new)
+            }
+        }))
+        .await
+        .expect("failed to connect to in-process mock chimera server");
+
+    ChimeraServiceClient::new(channel)
+}
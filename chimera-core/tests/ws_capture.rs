@@ -0,0 +1,99 @@
+//! Drives `WsCapture` against a local mock WebSocket endpoint: frame
+//! forwarding, the ping/pong keepalive, and the reconnect-with-backoff
+//! behavior on a dropped connection.
+
+#![cfg(feature = "mock-server")]
+
+mod support;
+
+use chimera_core::{BackoffConfig, WsCapture};
+use futures_util::{SinkExt, StreamExt};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+async fn chimera_client(
+) -> chimera_core::chimera::chimera_service_client::ChimeraServiceClient<tonic::transport::Channel>
+{
+    support::start(support::MockChimeraServer::new(Vec::new())).await
+}
+
+#[tokio::test]
+async fn capture_acks_pings_between_forwarded_frames_before_a_clean_close() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        ws.send(Message::Text("hello".into())).await.unwrap();
+        ws.send(Message::Binary(vec![1, 2, 3])).await.unwrap();
+
+        ws.send(Message::Ping(vec![9])).await.unwrap();
+        let pong = ws.next().await.unwrap().unwrap();
+        assert!(matches!(pong, Message::Pong(payload) if payload == vec![9]));
+
+        ws.close(None).await.unwrap();
+    });
+
+    let mut client = chimera_client().await;
+    let capture = WsCapture::new(format!("ws://{addr}"));
+
+    // A clean close ends the capture, and `run` just reconnects — it never
+    // returns on its own here, so bound it and let the server task's own
+    // assertions (the pong reply above) do the real checking.
+    let _ = tokio::time::timeout(Duration::from_millis(500), capture.run(&mut client)).await;
+
+    server.await.expect("server task panicked");
+}
+
+#[tokio::test]
+async fn run_backs_off_after_a_dropped_connection_instead_of_busy_looping() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let observed = Arc::new(Mutex::new(Vec::<Instant>::new()));
+    let observed_in_server = observed.clone();
+
+    tokio::spawn(async move {
+        // First connection: complete the handshake, then drop the raw TCP
+        // stream without a close frame — a dropped connection, not a clean
+        // close.
+        let (stream, _) = listener.accept().await.unwrap();
+        let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        observed_in_server.lock().unwrap().push(Instant::now());
+        drop(ws);
+
+        // Second connection: the reconnect attempt. Record when it arrives,
+        // then close cleanly so this task doesn't need to handle a third.
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        observed_in_server.lock().unwrap().push(Instant::now());
+        let _ = ws.close(None).await;
+    });
+
+    let mut client = chimera_client().await;
+    let backoff = BackoffConfig {
+        initial: Duration::from_millis(150),
+        max: Duration::from_millis(150),
+    };
+    let capture = WsCapture::new(format!("ws://{addr}")).backoff(backoff);
+
+    // `run` loops forever on non-fatal errors, so bound it: by the time both
+    // connection attempts above have been observed, the timing assertion
+    // below no longer needs `run` itself to return.
+    let _ = tokio::time::timeout(Duration::from_secs(2), capture.run(&mut client)).await;
+
+    let recorded = observed.lock().unwrap().clone();
+    assert_eq!(
+        recorded.len(),
+        2,
+        "expected exactly two connection attempts"
+    );
+    assert!(
+        recorded[1].duration_since(recorded[0]) >= Duration::from_millis(150),
+        "reconnect after a dropped connection should wait out the backoff delay, not retry immediately"
+    );
+}